@@ -168,6 +168,102 @@ impl<T, Header> HeaderSlice<T, Header> {
         ptr
     }
 
+    pub const fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns a view over the same allocation with the trailing slice shortened
+    /// to `new_len`, without moving or reallocating anything.
+    ///
+    /// Unlike the owned [`truncate`](Self::truncate), the view leaves the inline
+    /// `length` at offset 0 untouched, so it no longer matches the view's
+    /// trailing-slice length. That is harmless for a borrowed fat-pointer view,
+    /// but it means the view must never be erased and recovered through
+    /// [`Erasable::unerase`](thin_ptr::Erasable::unerase), which trusts that
+    /// stored length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is larger than the current length.
+    pub fn as_truncated(&self, new_len: usize) -> &HeaderSlice<T, Header> {
+        assert!(new_len <= self.slice.len(), "cannot grow past the current length");
+        unsafe { self.resized_unchecked(new_len) }
+    }
+
+    /// The mutable counterpart to [`as_truncated`](Self::as_truncated).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is larger than the current length.
+    pub fn as_truncated_mut(&mut self, new_len: usize) -> &mut HeaderSlice<T, Header> {
+        assert!(new_len <= self.slice.len(), "cannot grow past the current length");
+        unsafe { self.resized_unchecked_mut(new_len) }
+    }
+
+    /// # Safety
+    ///
+    /// `new_len` must not be larger than the current trailing-slice length.
+    pub unsafe fn resized_unchecked(&self, new_len: usize) -> &HeaderSlice<T, Header> {
+        &*(core::ptr::slice_from_raw_parts(self as *const Self as *const (), new_len)
+            as *const HeaderSlice<T, Header>)
+    }
+
+    /// # Safety
+    ///
+    /// `new_len` must not be larger than the current trailing-slice length.
+    pub unsafe fn resized_unchecked_mut(&mut self, new_len: usize) -> &mut HeaderSlice<T, Header> {
+        &mut *(core::ptr::slice_from_raw_parts_mut(self as *mut Self as *mut (), new_len)
+            as *mut HeaderSlice<T, Header>)
+    }
+
+    /// Drops the trailing `self.len() - new_len` elements in place, rewrites the
+    /// inline `length` to `new_len`, and shrinks the backing allocation to
+    /// `Self::layout_for(new_len)`.
+    ///
+    /// Because the stored `length` is kept in sync with the real trailing-slice
+    /// length and the allocation is resized to match, the resulting box stays
+    /// consistent for later [`Erasable::unerase`](thin_ptr::Erasable::unerase)
+    /// reads and drops with the correct layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is larger than the current length.
+    #[cfg(feature = "alloc")]
+    pub fn truncate(self: alloc::boxed::Box<Self>, new_len: usize) -> alloc::boxed::Box<Self> {
+        assert!(new_len <= self.slice.len(), "cannot grow past the current length");
+        let old_len = self.slice.len();
+        let old_layout = match Self::layout_for(old_len) {
+            Ok(layout) => layout,
+            Err(_) => unsafe { core::hint::unreachable_unchecked() },
+        };
+        let new_layout = match Self::layout_for(new_len) {
+            Ok(layout) => layout,
+            Err(_) => unsafe { core::hint::unreachable_unchecked() },
+        };
+
+        let raw = alloc::boxed::Box::into_raw(self);
+        unsafe {
+            let data = core::ptr::addr_of_mut!((*raw).slice) as *mut T;
+            core::ptr::slice_from_raw_parts_mut(data.add(new_len), old_len - new_len)
+                .drop_in_place();
+            core::ptr::addr_of_mut!((*raw).length).write(new_len);
+
+            let thin = raw as *mut ();
+            let thin = alloc::alloc::realloc(thin.cast(), old_layout, new_layout.size());
+            let Some(thin) = NonNull::new(thin) else {
+                alloc::alloc::handle_alloc_error(new_layout)
+            };
+
+            let raw = core::ptr::slice_from_raw_parts_mut(thin.as_ptr().cast::<()>(), new_len)
+                as *mut HeaderSlice<T, Header>;
+            alloc::boxed::Box::from_raw(raw)
+        }
+    }
+
     #[cfg(feature = "alloc")]
     pub fn try_new<I: IntoIterator<Item = T>>(
         header: Header,
@@ -227,13 +323,22 @@ impl<T, Header> HeaderSlice<T, Header> {
     }
 
     #[cfg(feature = "alloc")]
-    pub fn new<I: IntoIterator<Item = T>>(header: Header, iter: I) -> alloc::boxed::Box<Self>
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<A, I>(header: Header, iter: I) -> A
     where
+        A: AllocSliceDst<Self>,
+        I: IntoIterator<Item = T>,
         I::IntoIter: ExactSizeIterator,
     {
-        match Self::try_new(header, iter) {
-            Ok(x) => x,
-            Err(err) => err.handle(),
+        let iter = iter.into_iter();
+        let len = iter.len();
+
+        unsafe {
+            A::alloc_slice_dst(len, |ptr| {
+                if let Err(err) = Self::new_into(ptr, len, header, iter) {
+                    TryNewError::NotEnoughItems(err.drop_in_place()).handle()
+                }
+            })
         }
     }
 
@@ -263,6 +368,135 @@ impl<T, Header> HeaderSlice<T, Header> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T, Header> HeaderSlice<T, Header> {
+    pub fn try_new_in<A, I>(
+        alloc: A,
+        header: Header,
+        iter: I,
+    ) -> Result<allocator_api2::boxed::Box<Self, A>, TryNewError<Header>>
+    where
+        A: allocator_api2::alloc::Allocator,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let len = iter.len();
+
+        let ptr = match alloc_in(Self::layout_for(len), &alloc) {
+            Ok(ptr) => ptr,
+            Err(err) => return Err(err.with_header(header)),
+        };
+
+        match unsafe { Self::new_into(ptr, len, header, iter) } {
+            Ok(ptr) => Ok(unsafe { allocator_api2::boxed::Box::from_raw_in(ptr.as_ptr(), alloc) }),
+            Err(err) => {
+                let header = unsafe { err.drop_in_place() };
+                if let Ok(layout) = Self::layout_for(len) {
+                    unsafe { alloc.deallocate(ptr.cast(), layout) };
+                }
+                Err(TryNewError::NotEnoughItems(header))
+            }
+        }
+    }
+
+    pub fn new_in<A, I>(alloc: A, header: Header, iter: I) -> allocator_api2::boxed::Box<Self, A>
+    where
+        A: allocator_api2::alloc::Allocator,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        match Self::try_new_in(alloc, header, iter) {
+            Ok(x) => x,
+            Err(err) => err.handle(),
+        }
+    }
+
+    pub fn try_clone_from_in<A>(
+        alloc: A,
+        header: Header,
+        slice: &[T],
+    ) -> Result<allocator_api2::boxed::Box<Self, A>, TryNewError<Header>>
+    where
+        A: allocator_api2::alloc::Allocator,
+        T: Clone,
+    {
+        let ptr = match alloc_in(Self::layout_for(slice.len()), &alloc) {
+            Ok(ptr) => ptr,
+            Err(err) => return Err(err.with_header(header)),
+        };
+
+        let ptr = unsafe { Self::clone_from_into(ptr, header, slice) };
+
+        Ok(unsafe { allocator_api2::boxed::Box::from_raw_in(ptr.as_ptr(), alloc) })
+    }
+
+    pub fn clone_from_in<A>(
+        alloc: A,
+        header: Header,
+        slice: &[T],
+    ) -> allocator_api2::boxed::Box<Self, A>
+    where
+        A: allocator_api2::alloc::Allocator,
+        T: Clone,
+    {
+        match Self::try_clone_from_in(alloc, header, slice) {
+            Ok(x) => x,
+            Err(err) => err.handle(),
+        }
+    }
+
+    pub fn try_copy_from_in<A>(
+        alloc: A,
+        header: Header,
+        slice: &[T],
+    ) -> Result<allocator_api2::boxed::Box<Self, A>, TryNewError<Header>>
+    where
+        A: allocator_api2::alloc::Allocator,
+        T: Copy,
+    {
+        let ptr = match alloc_in(Self::layout_for(slice.len()), &alloc) {
+            Ok(ptr) => ptr,
+            Err(err) => return Err(err.with_header(header)),
+        };
+
+        let ptr = unsafe { Self::copy_from_into(ptr, header, slice) };
+
+        Ok(unsafe { allocator_api2::boxed::Box::from_raw_in(ptr.as_ptr(), alloc) })
+    }
+
+    pub fn copy_from_in<A>(
+        alloc: A,
+        header: Header,
+        slice: &[T],
+    ) -> allocator_api2::boxed::Box<Self, A>
+    where
+        A: allocator_api2::alloc::Allocator,
+        T: Copy,
+    {
+        match Self::try_copy_from_in(alloc, header, slice) {
+            Ok(x) => x,
+            Err(err) => err.handle(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn alloc_in<A: allocator_api2::alloc::Allocator>(
+    layout: Result<Layout, LayoutError>,
+    alloc: &A,
+) -> Result<NonNull<()>, TryNewError<()>> {
+    let layout = match layout {
+        Ok(layout) => layout,
+        Err(_) => return Err(TryNewError::LayoutTooLarge(())),
+    };
+
+    match alloc.allocate(layout) {
+        Ok(ptr) => Ok(ptr.cast()),
+        Err(_) => Err(TryNewError::AllocError((), layout)),
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl TryNewError<()> {
     fn with_header<Header>(self, header: Header) -> TryNewError<Header> {
@@ -323,6 +557,65 @@ impl<Header> HeaderStr<Header> {
         Ok(part2.pad_to_align())
     }
 
+    pub const fn len(&self) -> usize {
+        self.str.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.str.is_empty()
+    }
+
+    /// Returns a view over the same allocation with the trailing string shortened
+    /// to `new_len` bytes, without moving or reallocating anything.
+    ///
+    /// As with [`HeaderSlice::as_truncated`], the view's inline `length` is left
+    /// at the original value, so the view must not be erased and recovered
+    /// through [`Erasable::unerase`](thin_ptr::Erasable::unerase).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is larger than the current length or does not fall on
+    /// a `char` boundary.
+    pub fn as_truncated(&self, new_len: usize) -> &HeaderStr<Header> {
+        assert!(
+            self.str.is_char_boundary(new_len),
+            "new_len must not exceed the current length and must be a char boundary"
+        );
+        unsafe { self.resized_unchecked(new_len) }
+    }
+
+    /// The mutable counterpart to [`as_truncated`](Self::as_truncated).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is larger than the current length or does not fall on
+    /// a `char` boundary.
+    pub fn as_truncated_mut(&mut self, new_len: usize) -> &mut HeaderStr<Header> {
+        assert!(
+            self.str.is_char_boundary(new_len),
+            "new_len must not exceed the current length and must be a char boundary"
+        );
+        unsafe { self.resized_unchecked_mut(new_len) }
+    }
+
+    /// # Safety
+    ///
+    /// `new_len` must not be larger than the current length and must fall on a
+    /// `char` boundary.
+    pub unsafe fn resized_unchecked(&self, new_len: usize) -> &HeaderStr<Header> {
+        &*(core::ptr::slice_from_raw_parts(self as *const Self as *const (), new_len)
+            as *const HeaderStr<Header>)
+    }
+
+    /// # Safety
+    ///
+    /// `new_len` must not be larger than the current length and must fall on a
+    /// `char` boundary.
+    pub unsafe fn resized_unchecked_mut(&mut self, new_len: usize) -> &mut HeaderStr<Header> {
+        &mut *(core::ptr::slice_from_raw_parts_mut(self as *mut Self as *mut (), new_len)
+            as *mut HeaderStr<Header>)
+    }
+
     fn cast(ptr: NonNull<HeaderSlice<u8, Header>>) -> NonNull<HeaderStr<Header>> {
         unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut HeaderStr<Header>) }
     }
@@ -338,6 +631,400 @@ impl<Header> HeaderStr<Header> {
             s.as_bytes(),
         ))
     }
+
+    #[cfg(feature = "alloc")]
+    pub fn try_new_into_in<A>(
+        alloc: A,
+        s: &str,
+        header: Header,
+    ) -> Result<allocator_api2::boxed::Box<Self, A>, TryNewError<Header>>
+    where
+        A: allocator_api2::alloc::Allocator,
+    {
+        let ptr = match alloc_in(Self::layout_for(s.len()), &alloc) {
+            Ok(ptr) => ptr,
+            Err(err) => return Err(err.with_header(header)),
+        };
+
+        let ptr = unsafe { Self::new_into(ptr, s, header) };
+
+        Ok(unsafe { allocator_api2::boxed::Box::from_raw_in(ptr.as_ptr(), alloc) })
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn new_into_in<A>(alloc: A, s: &str, header: Header) -> allocator_api2::boxed::Box<Self, A>
+    where
+        A: allocator_api2::alloc::Allocator,
+    {
+        match Self::try_new_into_in(alloc, s, header) {
+            Ok(x) => x,
+            Err(err) => err.handle(),
+        }
+    }
+}
+
+/// An owning, single-word pointer to a custom DST.
+///
+/// Unlike [`alloc::boxed::Box<S>`], which is a fat pointer (two words),
+/// `ThinBox` stores only the data address and recovers the real DST pointer on
+/// deref via [`Erasable::unerase`](thin_ptr::Erasable::unerase), which reads the
+/// inline `length` stored at offset 0.
+#[cfg(feature = "alloc")]
+pub struct ThinBox<S: thin_ptr::Erasable + ?Sized> {
+    ptr: NonNull<()>,
+    _owns: core::marker::PhantomData<alloc::boxed::Box<S>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, Header> ThinBox<HeaderSlice<T, Header>> {
+    pub fn new<I: IntoIterator<Item = T>>(header: Header, iter: I) -> Self
+    where
+        I::IntoIter: ExactSizeIterator,
+    {
+        Self::from_box(HeaderSlice::new(header, iter))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: thin_ptr::Erasable + ?Sized> ThinBox<S> {
+    pub fn from_box(boxed: alloc::boxed::Box<S>) -> Self {
+        let ptr = unsafe { NonNull::new_unchecked(alloc::boxed::Box::into_raw(boxed)) };
+        Self {
+            ptr: ptr.cast(),
+            _owns: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: thin_ptr::Erasable + ?Sized> core::ops::Deref for ThinBox<S> {
+    type Target = S;
+
+    #[inline]
+    fn deref(&self) -> &S {
+        unsafe { S::unerase(self.ptr).as_ref() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: thin_ptr::Erasable + ?Sized> core::ops::DerefMut for ThinBox<S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut S {
+        unsafe { S::unerase(self.ptr).as_mut() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: thin_ptr::Erasable + ?Sized> Drop for ThinBox<S> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = S::unerase(self.ptr);
+            let layout = Layout::for_value(ptr.as_ref());
+            core::ptr::drop_in_place(ptr.as_ptr());
+            alloc::alloc::dealloc(self.ptr.as_ptr().cast(), layout);
+        }
+    }
+}
+
+/// A header wrapper that stores a strong reference count alongside the user's
+/// header.
+///
+/// Wrapping the header this way keeps the `#[repr(C)]` layout of the backing
+/// [`HeaderSlice`] as `length, count, header, slice`, so the inline `length` at
+/// offset 0 stays where [`Erasable::unerase`](thin_ptr::Erasable::unerase)
+/// expects it and a clone is just a count increment away. This is an internal
+/// implementation detail of [`ThinArc`]/[`ThinRc`] and never surfaces in their
+/// public API.
+#[cfg(feature = "alloc")]
+#[repr(C)]
+struct WithRefCount<C, Header> {
+    count: C,
+    header: Header,
+}
+
+/// The backing [`HeaderSlice`] owned by a [`ThinArc`], with the atomic strong
+/// count folded into the header region.
+#[cfg(feature = "alloc")]
+type ThinArcInner<T, Header> =
+    HeaderSlice<T, WithRefCount<core::sync::atomic::AtomicUsize, Header>>;
+
+/// The backing [`HeaderSlice`] owned by a [`ThinRc`], with the non-atomic strong
+/// count folded into the header region.
+#[cfg(feature = "alloc")]
+type ThinRcInner<T, Header> = HeaderSlice<T, WithRefCount<core::cell::Cell<usize>, Header>>;
+
+/// A thin, atomically reference-counted owner of a [`HeaderSlice`].
+///
+/// As [`ThinBox`] is to [`alloc::boxed::Box`], this is to [`alloc::sync::Arc`]:
+/// it is one word wide, it stores
+/// only the data address and recovers the real DST pointer via
+/// [`Erasable::unerase`](thin_ptr::Erasable::unerase). The strong count lives in
+/// the header region, so cloning only touches an atomic.
+///
+/// Note: unlike [`alloc::sync::Arc`] this intentionally does *not* implement
+/// `Deref`. A `Deref` target of `HeaderSlice<T, Header>` is not layout
+/// compatible (the stored header is an internal count wrapper), so the
+/// count-free [`header`](Self::header)/[`slice`](Self::slice) accessors are the
+/// public borrowing surface instead.
+#[cfg(feature = "alloc")]
+pub struct ThinArc<T, Header = ()> {
+    ptr: NonNull<()>,
+    _owns: core::marker::PhantomData<alloc::boxed::Box<ThinArcInner<T, Header>>>,
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send + Sync, Header: Send + Sync> Send for ThinArc<T, Header> {}
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send + Sync, Header: Send + Sync> Sync for ThinArc<T, Header> {}
+
+#[cfg(feature = "alloc")]
+impl<T, Header> ThinArc<T, Header> {
+    pub fn new<I: IntoIterator<Item = T>>(header: Header, iter: I) -> Self
+    where
+        I::IntoIter: ExactSizeIterator,
+    {
+        let boxed = HeaderSlice::new(
+            WithRefCount {
+                count: core::sync::atomic::AtomicUsize::new(1),
+                header,
+            },
+            iter,
+        );
+        let ptr = unsafe { NonNull::new_unchecked(alloc::boxed::Box::into_raw(boxed)) };
+        Self {
+            ptr: ptr.cast(),
+            _owns: core::marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ThinArcInner<T, Header> {
+        unsafe { <ThinArcInner<T, Header> as thin_ptr::Erasable>::unerase(self.ptr).as_ref() }
+    }
+
+    /// Borrows the user header without touching the reference count.
+    #[inline]
+    pub fn header(&self) -> &Header {
+        &self.inner().header.header
+    }
+
+    /// Borrows the trailing slice without touching the reference count.
+    #[inline]
+    pub fn slice(&self) -> &[T] {
+        &self.inner().slice
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, Header> Clone for ThinArc<T, Header> {
+    fn clone(&self) -> Self {
+        self.inner()
+            .header
+            .count
+            .fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        Self {
+            ptr: self.ptr,
+            _owns: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, Header> Drop for ThinArc<T, Header> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = <ThinArcInner<T, Header> as thin_ptr::Erasable>::unerase(self.ptr);
+            if ptr
+                .as_ref()
+                .header
+                .count
+                .fetch_sub(1, core::sync::atomic::Ordering::Release)
+                != 1
+            {
+                return;
+            }
+            core::sync::atomic::fence(core::sync::atomic::Ordering::Acquire);
+            let layout = Layout::for_value(ptr.as_ref());
+            core::ptr::drop_in_place(ptr.as_ptr());
+            alloc::alloc::dealloc(self.ptr.as_ptr().cast(), layout);
+        }
+    }
+}
+
+/// A thin, non-atomically reference-counted owner of a [`HeaderSlice`].
+///
+/// The single-threaded analogue of [`ThinArc`]: the strong count is a
+/// [`Cell<usize>`](core::cell::Cell) stored in the header region, so it is one
+/// word wide but not `Send`/`Sync`.
+///
+/// Like [`ThinArc`] it deliberately does not implement `Deref`; use the
+/// count-free [`header`](Self::header)/[`slice`](Self::slice) accessors.
+#[cfg(feature = "alloc")]
+pub struct ThinRc<T, Header = ()> {
+    ptr: NonNull<()>,
+    _owns: core::marker::PhantomData<alloc::boxed::Box<ThinRcInner<T, Header>>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, Header> ThinRc<T, Header> {
+    pub fn new<I: IntoIterator<Item = T>>(header: Header, iter: I) -> Self
+    where
+        I::IntoIter: ExactSizeIterator,
+    {
+        let boxed = HeaderSlice::new(
+            WithRefCount {
+                count: core::cell::Cell::new(1),
+                header,
+            },
+            iter,
+        );
+        let ptr = unsafe { NonNull::new_unchecked(alloc::boxed::Box::into_raw(boxed)) };
+        Self {
+            ptr: ptr.cast(),
+            _owns: core::marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ThinRcInner<T, Header> {
+        unsafe { <ThinRcInner<T, Header> as thin_ptr::Erasable>::unerase(self.ptr).as_ref() }
+    }
+
+    /// Borrows the user header without touching the reference count.
+    #[inline]
+    pub fn header(&self) -> &Header {
+        &self.inner().header.header
+    }
+
+    /// Borrows the trailing slice without touching the reference count.
+    #[inline]
+    pub fn slice(&self) -> &[T] {
+        &self.inner().slice
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, Header> Clone for ThinRc<T, Header> {
+    fn clone(&self) -> Self {
+        let count = &self.inner().header.count;
+        count.set(count.get() + 1);
+        Self {
+            ptr: self.ptr,
+            _owns: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, Header> Drop for ThinRc<T, Header> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = <ThinRcInner<T, Header> as thin_ptr::Erasable>::unerase(self.ptr);
+            let count = &ptr.as_ref().header.count;
+            count.set(count.get() - 1);
+            if count.get() != 0 {
+                return;
+            }
+            let layout = Layout::for_value(ptr.as_ref());
+            core::ptr::drop_in_place(ptr.as_ptr());
+            alloc::alloc::dealloc(self.ptr.as_ptr().cast(), layout);
+        }
+    }
+}
+
+/// Containers that can allocate and initialize a custom DST in a single step.
+///
+/// `S` is the (unsized) target type; `init` is handed a freshly allocated block
+/// laid out for `S` and is responsible for running [`HeaderSlice::new_into`] to
+/// populate it, including writing the inline `length` at offset 0. Implementing
+/// this for a container is all it takes to let [`HeaderSlice::new`] target it, so
+/// the caller picks the container at the call site:
+///
+/// ```ignore
+/// let boxed: Box<_> = HeaderSlice::new((), 0..4);
+/// let rc: alloc::rc::Rc<_> = HeaderSlice::new((), 0..4);
+/// let thin: ThinBox<_> = HeaderSlice::new((), 0..4);
+/// ```
+///
+/// The [`Box`](alloc::boxed::Box) and [`ThinBox`] impls initialize their own
+/// allocation in place. The [`Rc`](alloc::rc::Rc)/[`Arc`](alloc::sync::Arc)
+/// impls cannot adopt a foreign allocation — `std` reserves space for its own
+/// strong/weak counts — so they build a `Box` first and convert, which costs a
+/// second allocation and a move of the DST.
+///
+/// # Safety
+///
+/// Implementors must hand `init` a block allocated for exactly
+/// `HeaderSlice::layout_for(len)` (suitably aligned and writable), call `init`
+/// exactly once, and only recover the DST pointer once `init` has returned
+/// normally — at which point the block is fully initialized.
+#[cfg(feature = "alloc")]
+pub unsafe trait AllocSliceDst<S: ?Sized> {
+    /// # Safety
+    ///
+    /// `init` must fully initialize the block it is handed before returning
+    /// normally, so that recovering the DST pointer from it is sound.
+    unsafe fn alloc_slice_dst(len: usize, init: impl FnOnce(NonNull<()>)) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T, Header> AllocSliceDst<HeaderSlice<T, Header>>
+    for alloc::boxed::Box<HeaderSlice<T, Header>>
+{
+    unsafe fn alloc_slice_dst(len: usize, init: impl FnOnce(NonNull<()>)) -> Self {
+        let ptr = match alloc::<T, Header>(len) {
+            Ok(ptr) => ptr,
+            Err(err) => err.handle(),
+        };
+
+        init(ptr);
+
+        unsafe {
+            alloc::boxed::Box::from_raw(
+                <HeaderSlice<T, Header> as thin_ptr::Erasable>::unerase(ptr).as_ptr(),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T, Header> AllocSliceDst<HeaderSlice<T, Header>>
+    for alloc::rc::Rc<HeaderSlice<T, Header>>
+{
+    unsafe fn alloc_slice_dst(len: usize, init: impl FnOnce(NonNull<()>)) -> Self {
+        let boxed = unsafe {
+            <alloc::boxed::Box<HeaderSlice<T, Header>> as AllocSliceDst<_>>::alloc_slice_dst(
+                len, init,
+            )
+        };
+        alloc::rc::Rc::from(boxed)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T, Header> AllocSliceDst<HeaderSlice<T, Header>>
+    for alloc::sync::Arc<HeaderSlice<T, Header>>
+{
+    unsafe fn alloc_slice_dst(len: usize, init: impl FnOnce(NonNull<()>)) -> Self {
+        let boxed = unsafe {
+            <alloc::boxed::Box<HeaderSlice<T, Header>> as AllocSliceDst<_>>::alloc_slice_dst(
+                len, init,
+            )
+        };
+        alloc::sync::Arc::from(boxed)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T, Header> AllocSliceDst<HeaderSlice<T, Header>> for ThinBox<HeaderSlice<T, Header>> {
+    unsafe fn alloc_slice_dst(len: usize, init: impl FnOnce(NonNull<()>)) -> Self {
+        let boxed = unsafe {
+            <alloc::boxed::Box<HeaderSlice<T, Header>> as AllocSliceDst<_>>::alloc_slice_dst(
+                len, init,
+            )
+        };
+        ThinBox::from_box(boxed)
+    }
 }
 
 struct SliceWriter<T> {